@@ -1,38 +1,67 @@
 #![feature(negative_impls)]
 #![deny(unsafe_op_in_unsafe_fn)]
-use core::mem;
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
 use core::ptr;
 
+/// A node's storage, as handed to [`IntrusiveList::with_cons_slice`]'s `scratch` parameter. Its
+/// fields are private — callers only ever need to name the type to build an uninitialized array of
+/// them, never to construct or read one directly.
 #[derive(Clone, Copy)]
-struct IntrusiveListNode<T> {
+pub struct IntrusiveListNode<T, const ID: u64 = 0> {
     value: ptr::NonNull<T>,
-    next: Option<ptr::NonNull<IntrusiveListNode<T>>>,
+    next: Option<NodePtr<T, ID>>,
+    prev: Option<NodePtr<T, ID>>,
+    /// Set once this node has been unlinked by something other than the `with_cons`/
+    /// `with_cons_slice` call that owns it (a [`CursorMut::remove_current`] on it, or a
+    /// [`CursorMut::splice_after`] moving it into another list). Once set, that owning call's
+    /// unwind must not re-derive `head`/`tail` from this node's own (now stale) `next`/`prev`:
+    /// whoever unlinked it already repaired the list it actually belongs to.
+    detached: bool,
 }
 
-pub struct IntrusiveList<T> {
-    head: Option<ptr::NonNull<IntrusiveListNode<T>>>,
+/// A pointer to a node, wrapped in its `UnsafeCell` so that shared reads (through `iter`/`head`)
+/// and the single live exclusive write (through `with_cons`/`iter_mut`/the cursor) can both reach
+/// the same memory without inviting the compiler to assume it's `noalias`.
+type NodePtr<T, const ID: u64 = 0> = ptr::NonNull<UnsafeCell<IntrusiveListNode<T, ID>>>;
+
+/// Project a node pointer to a shared reference to its contents.
+///
+/// SAFE to call as long as the list's exclusivity discipline holds: no exclusive projection
+/// ([`node_mut`]) of the same node may be live at the same time as this one.
+unsafe fn node_ref<'a, T, const ID: u64>(node: NodePtr<T, ID>) -> &'a IntrusiveListNode<T, ID> {
+    // SAFE: see fn docs; the pointer comes from a node that is still linked into some list.
+    unsafe { &*node.as_ref().get() }
 }
 
-impl<T> Default for IntrusiveList<T> {
-    fn default() -> Self {
-        Self { head: None }
-    }
+/// Project a node pointer to the one live mutable reference to its contents, routing through the
+/// safe `UnsafeCell::get_mut` once the pointer itself has been dereferenced so only the pointer
+/// follow, not the cell projection, needs `unsafe`.
+///
+/// SAFE to call as long as the list's exclusivity discipline holds: no other projection of the
+/// same node, shared or exclusive, may be live at the same time as this one.
+unsafe fn node_mut<'a, T, const ID: u64>(mut node: NodePtr<T, ID>) -> &'a mut IntrusiveListNode<T, ID> {
+    // SAFE: see fn docs; the pointer comes from a node that is still linked into some list.
+    unsafe { node.as_mut() }.get_mut()
 }
 
-/// A helper structure to implement `Debug`, since I need to have
-/// exclusive read access to it.
-pub struct Dbg<'a, T>(&'a mut IntrusiveList<T>);
+pub struct IntrusiveList<T, const ID: u64 = 0> {
+    head: Option<NodePtr<T, ID>>,
+    tail: Option<NodePtr<T, ID>>,
+}
 
-impl<'a, T: core::fmt::Debug + 'a> core::fmt::Debug for Dbg<'a, T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut builder = f.debug_list();
-        let mut current = self.0.head;
-        while let Some(curr) = current {
-            builder.entry(unsafe { curr.as_ref().value.as_ref() });
-            current = unsafe { curr.as_ref().next };
+impl<T, const ID: u64> Default for IntrusiveList<T, ID> {
+    fn default() -> Self {
+        Self {
+            head: None,
+            tail: None,
         }
+    }
+}
 
-        builder.finish()
+impl<T: core::fmt::Debug, const ID: u64> core::fmt::Debug for IntrusiveList<T, ID> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
     }
 }
 
@@ -40,100 +69,416 @@ impl<'a, T: core::fmt::Debug + 'a> core::fmt::Debug for Dbg<'a, T> {
 /// Due to safety reasons, the only possible thing to do with this is to read the head and to cons
 /// temporally.
 ///
-/// A direct `Debug` implementation is not possible since immutable references to this structure
-/// aren't safe and I could not tell the compiler that `&IntrusiveList<T>: !Send + !Sync`. If you
-/// want to debug the list use the [`debug`](IntrusiveList::debug) method.
+/// `ID` lets the same `T` be consed into several lists at once: `IntrusiveList<T, 0>` and
+/// `IntrusiveList<T, 1>` are unrelated types, so a `with_cons` call on one allocates its own node
+/// on its own stack frame and can never alias the `next`/`prev` pointers a `with_cons` call on the
+/// other is using. This is what lets one stack value belong to, say, a "ready queue" and an "all
+/// tasks" list at the same time, the way a single object can sit in several intrusive lists in the
+/// Rust-for-Linux list — reborrow the value out of the first list's own `head_mut` to hand it to
+/// the second `with_cons` call, rather than deriving a second, independent `&mut T` to the same
+/// place: the borrow checker then still only ever sees one live mutable reference.
+///
+/// The list is doubly-linked: it keeps both `head` and `tail`, and every node keeps a `prev`
+/// alongside its `next`, so it can be walked back-to-front via [`Iter`]/[`IterMut`]'s
+/// `DoubleEndedIterator` impl as well as forward.
+///
+/// Nodes store their links behind an [`UnsafeCell`] (see [`NodePtr`]), which is what lets `iter`
+/// take `&self` and `Debug` be implemented directly: reads
+/// and the single live write are both going through a cell that's explicitly opted out of the
+/// no-alias assumption, rather than through bare `&T`/`&mut T` that happen to overlap. The list is
+/// still kept `!Send + !Sync`, and only one exclusive projection of any node may be live at a
+/// time, a discipline enforced by `with_cons`/`iter_mut`/`CursorMut` all requiring `&mut self`.
 ///
 /// A `Clone` implementation is not sound due to the intrusive list containing `&mut T`s
 /// disguised in `ptr::NonNull<T>` to allow for multiple lifetimes to participate.
-impl<T> IntrusiveList<T> {
+impl<T, const ID: u64> IntrusiveList<T, ID> {
     /// Adds the reference, runs `cont`, pops the reference.
     pub fn with_cons<O>(&mut self, value: &mut T, cont: impl FnOnce(&mut Self) -> O) -> O {
         // NOTE: no checks are needed since we're being given a *mutable reference*, which is NOT
         // copyable and MUST be moved.
-        let mut new_node = IntrusiveListNode {
+        let mut new_node = UnsafeCell::new(IntrusiveListNode {
             // SAFE: `value` is a reference
             value: unsafe { ptr::NonNull::new_unchecked(value) },
             next: self.head.take(),
+            prev: None,
+            detached: false,
+        });
+        // SAFE: `new_node` is a local that outlives every use of this pointer below, since none
+        // of them escape this function.
+        let new_node_ptr = unsafe { ptr::NonNull::new_unchecked(&mut new_node) };
+        match new_node.get_mut().next {
+            Some(old_head) => unsafe { node_mut(old_head) }.prev = Some(new_node_ptr),
+            None => self.tail = Some(new_node_ptr),
+        }
+        self.head = Some(new_node_ptr);
+        let result = cont(self);
+
+        // Unlink `new_node`, repairing whichever neighbor used to point at it — unless it was
+        // already unlinked out from under us (e.g. a nested `CursorMut::remove_current`), in
+        // which case whoever did that already repaired `head`/`tail` and `new_node`'s own
+        // `next`/`prev` are stale.
+        if !new_node.get_mut().detached {
+            self.head = new_node.get_mut().next;
+            match new_node.get_mut().next {
+                Some(next) => unsafe { node_mut(next) }.prev = new_node.get_mut().prev,
+                None => self.tail = new_node.get_mut().prev,
+            }
+        }
+
+        result
+    }
+
+    /// Cons a whole slice of references at once, front-to-back, instead of nesting one
+    /// `with_cons` call per element: building a list of `N` stack values via `N` nested closures
+    /// blows the call stack long before `N` gets large. `scratch` is the backing storage for the
+    /// `N` temporary nodes, one slot per element of `values` — typically a fixed-size array the
+    /// caller puts on their own stack, so this never allocates. Panics if the two slices' lengths
+    /// differ.
+    ///
+    /// A `CursorMut` may remove the run's first or last node before this call returns: doing so
+    /// already correctly weaves whatever remains of the run into the list, so the remainder is
+    /// left exactly as the removal left it rather than being forced back out when this call
+    /// returns. Only the two boundary nodes are tracked this way, though — removing a node from
+    /// the *middle* of the run without touching either end is not: the next `cont` return still
+    /// reverts `head`/`tail` all the way back, taking the untouched boundary nodes with it.
+    pub fn with_cons_slice<O>(
+        &mut self,
+        values: &mut [T],
+        scratch: &mut [MaybeUninit<UnsafeCell<IntrusiveListNode<T, ID>>>],
+        cont: impl FnOnce(&mut Self) -> O,
+    ) -> O {
+        assert_eq!(
+            scratch.len(),
+            values.len(),
+            "`scratch` must have exactly one slot per element of `values`"
+        );
+        if values.is_empty() {
+            return cont(self);
+        }
+
+        for (slot, value) in scratch.iter_mut().zip(values.iter_mut()) {
+            slot.write(UnsafeCell::new(IntrusiveListNode {
+                // SAFE: `value` is a reference.
+                value: unsafe { ptr::NonNull::new_unchecked(value) },
+                next: None,
+                prev: None,
+                detached: false,
+            }));
+        }
+        // SAFE: every slot was just initialized above, and `scratch` outlives every use of these
+        // pointers below, since none of them escape this function.
+        let node_at = |scratch: &mut [MaybeUninit<UnsafeCell<IntrusiveListNode<T, ID>>>], i: usize| unsafe {
+            ptr::NonNull::new_unchecked(scratch[i].as_mut_ptr())
         };
-        self.head = Some(unsafe { ptr::NonNull::new_unchecked(&mut new_node) });
+
+        for i in 0..scratch.len() {
+            let prev = if i == 0 { None } else { Some(node_at(scratch, i - 1)) };
+            let next = if i + 1 < scratch.len() {
+                Some(node_at(scratch, i + 1))
+            } else {
+                None
+            };
+            // SAFE: `node_at(scratch, i)` is one of the pointers we just built above.
+            let node = unsafe { node_mut(node_at(scratch, i)) };
+            node.prev = prev;
+            node.next = next;
+        }
+
+        let first = node_at(scratch, 0);
+        let last = node_at(scratch, scratch.len() - 1);
+
+        let old_head = self.head.take();
+        match old_head {
+            Some(head) => unsafe { node_mut(head).prev = Some(last) },
+            None => self.tail = Some(last),
+        }
+        unsafe { node_mut(last).next = old_head };
+        self.head = Some(first);
+
         let result = cont(self);
 
-        self.head = new_node.next;
+        // Unlink the whole run, repairing whichever neighbor continues from its last node by now
+        // (read from `last`, like `with_cons` reads its own node's `next`, in case a cursor
+        // removed the node that followed the run at call time) — unless either boundary was
+        // already touched by a cursor operation (`first`/`last` detached, same flag and
+        // reasoning as `with_cons`'s own skip). `scratch` is caller-owned, not a local dropped at
+        // the end of this call, so whatever of the run a cursor operation has already correctly
+        // woven into the list is left that way rather than forced back out.
+        if !unsafe { node_ref(first) }.detached && !unsafe { node_ref(last) }.detached {
+            // SAFE: `last` is still linked, so its `next` is the run's current continuation.
+            let continuation = unsafe { node_ref(last).next };
+            self.head = continuation;
+            match continuation {
+                Some(head) => unsafe { node_mut(head).prev = None },
+                None => self.tail = None,
+            }
+        }
 
         result
     }
 
     pub fn head(&self) -> Option<&T> {
-        self.head
-            .map(|node| unsafe { node.as_ref().value.as_ref() })
+        self.head.map(|node| unsafe {
+            // SAFE: valid by list impl.
+            node_ref(node).value.as_ref()
+        })
     }
 
     pub fn head_mut(&mut self) -> Option<&mut T> {
-        self.head
-            .map(|mut node| unsafe { node.as_mut().value.as_mut() })
+        self.head.map(|node| unsafe {
+            // SAFE: valid by list impl.
+            node_mut(node).value.as_mut()
+        })
+    }
+
+    pub fn tail(&self) -> Option<&T> {
+        self.tail.map(|node| unsafe {
+            // SAFE: valid by list impl.
+            node_ref(node).value.as_ref()
+        })
+    }
+
+    pub fn tail_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|node| unsafe {
+            // SAFE: valid by list impl.
+            node_mut(node).value.as_mut()
+        })
     }
 
     /// Get an iterator to immutable references of the list values.
-    /// NOTE: we can't use an immutable reference due to the possibility of iterator invalidation in
-    /// multithreaded code. Even though we don't mutate the structure, `&mut` ensures that
-    /// have *exclusive* access to the list, which means no iterator invalidation is possible.
-    pub fn iter(&mut self) -> Iter<'_, T> {
+    pub fn iter(&self) -> Iter<'_, T, ID> {
         Iter {
-            current: self.head,
+            front: self.head,
+            back: self.tail,
             _phantom: core::marker::PhantomData,
         }
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, ID> {
         IterMut {
-            current: self.head,
+            front: self.head,
+            back: self.tail,
             _phantom: core::marker::PhantomData,
         }
     }
 
-    pub fn debug(&mut self) -> Dbg<'_, T> {
-        Dbg(self)
+    /// Get a cursor positioned at the head of the list.
+    ///
+    /// The cursor can walk forward, remove the node it is sitting on, and graft another list in
+    /// after its position. It can never move backward, since there is no owned storage to give a
+    /// removed node back to: the frames it points into are still live on some caller's stack.
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T, ID> {
+        CursorMut {
+            current: self.head,
+            prev: None,
+            list: self,
+        }
     }
 }
 
-impl<T> !Send for IntrusiveList<T> {}
-impl<T> !Sync for IntrusiveList<T> {}
+impl<T, const ID: u64> !Send for IntrusiveList<T, ID> {}
+impl<T, const ID: u64> !Sync for IntrusiveList<T, ID> {}
 // FIXME: impl<T> !Send for &IntrusiveList<T>?
 
-pub struct Iter<'a, T> {
-    current: Option<ptr::NonNull<IntrusiveListNode<T>>>,
+/// A cursor that can walk an [`IntrusiveList`] forward, unlink the node it sits on, and splice
+/// another list in after its position.
+///
+/// There is no `move_prev`: a removed node has nowhere to go back to, since the storage it points
+/// into belongs to whichever stack frame called [`with_cons`](IntrusiveList::with_cons), not to
+/// this list. (The list itself is doubly-linked, but the cursor keeps to the same forward-only
+/// discipline `with_cons` relies on.)
+pub struct CursorMut<'a, T, const ID: u64 = 0> {
+    list: &'a mut IntrusiveList<T, ID>,
+    prev: Option<NodePtr<T, ID>>,
+    current: Option<NodePtr<T, ID>>,
+}
+
+impl<'a, T, const ID: u64> CursorMut<'a, T, ID> {
+    /// The value the cursor currently sits on, if it hasn't walked off the end.
+    pub fn current(&self) -> Option<&T> {
+        self.current.map(|node| unsafe {
+            // SAFE: valid by list impl.
+            node_ref(node).value.as_ref()
+        })
+    }
+
+    /// The value the cursor currently sits on, if it hasn't walked off the end.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.current.map(|node| unsafe {
+            // SAFE: valid by list impl.
+            node_mut(node).value.as_mut()
+        })
+    }
+
+    /// Advance the cursor to the next node, if any.
+    pub fn move_next(&mut self) {
+        if let Some(curr) = self.current {
+            self.prev = self.current;
+            // SAFE: valid by list impl.
+            self.current = unsafe { node_ref(curr).next };
+        }
+    }
+
+    /// Unlinks the node the cursor sits on, rewiring `prev.next = current.next` and
+    /// `current.next.prev = prev` (fixing up `head`/`tail` at either edge), and returns a mutable
+    /// reference to its value. The cursor is left sitting on the node that followed it.
+    ///
+    /// The removed node may belong to an outer (non-innermost) `with_cons` frame than the one the
+    /// cursor was obtained in — that frame is still live on the stack and will unwind later, and
+    /// it must not then re-derive `head`/`tail` from its own now-stale `next`/`prev`, since we've
+    /// already repaired the list around it here. So the node is also marked `detached` to tell its
+    /// owning `with_cons` to skip that repair when it unwinds.
+    pub fn remove_current(&mut self) -> Option<&'a mut T> {
+        let curr = self.current.take()?;
+        // SAFE: valid by list impl.
+        let next = unsafe { node_ref(curr).next };
+        match self.prev {
+            Some(prev) => unsafe { node_mut(prev) }.next = next,
+            None => self.list.head = next,
+        }
+        match next {
+            Some(next_node) => unsafe { node_mut(next_node) }.prev = self.prev,
+            None => self.list.tail = self.prev,
+        }
+        self.current = next;
+        // SAFE: valid by list impl.
+        unsafe { node_mut(curr) }.detached = true;
+        // SAFE: the removed node's value is borrowed for as long as the list itself, and we are
+        // handing out the one live reference to it.
+        Some(unsafe { node_mut(curr).value.as_mut() })
+    }
+
+    /// The node right before the cursor's insertion point: `current` itself if the cursor sits on
+    /// a node, or `prev` if it has walked off the end (`None` for both means the list is empty).
+    fn insertion_point(&self) -> Option<NodePtr<T, ID>> {
+        self.current.or(self.prev)
+    }
+
+    /// Grafts the entire chain of `other` in after the cursor position, relinking the two chains
+    /// in O(1) by reusing `other`'s tail pointer, and leaving `other` empty.
+    ///
+    /// Every node in `other`'s chain still belongs to a live `with_cons`/`with_cons_slice` frame
+    /// somewhere in `other`'s own call stack, and each of those frames will unwind later expecting
+    /// to repair `other`'s `head`/`tail` from its own node. Since the node is now part of `self`
+    /// instead, that repair must not run — so this walks the whole grafted chain once and marks
+    /// every node `detached`, same as [`Self::remove_current`] does for a single node. The caller
+    /// still must make sure `other`'s frames stay live for as long as `self` can reach the grafted
+    /// nodes: once `other`'s frames return, those nodes are gone even though `self` now links
+    /// through them.
+    pub fn splice_after(&mut self, other: &mut IntrusiveList<T, ID>) {
+        let other_head = match other.head.take() {
+            Some(head) => head,
+            None => return,
+        };
+        let other_tail = other.tail.take().expect("non-empty list has a tail");
+
+        let mut to_mark = Some(other_head);
+        while let Some(node) = to_mark {
+            // SAFE: every node from `other_head` to `other_tail` is still live: they belong to
+            // frames that are still on the stack somewhere in `other`'s call chain.
+            let node = unsafe { node_mut(node) };
+            to_mark = node.next;
+            node.detached = true;
+        }
+
+        let insertion_point = self.insertion_point();
+        let continuation = match insertion_point {
+            // SAFE: valid by list impl.
+            Some(node) => unsafe { node_ref(node).next },
+            None => self.list.head,
+        };
+
+        // SAFE: `other_head`/`other_tail` come from the now-emptied `other`, and `continuation`
+        // comes from the live `self.list`.
+        unsafe {
+            node_mut(other_head).prev = insertion_point;
+            node_mut(other_tail).next = continuation;
+        }
+        match insertion_point {
+            Some(node) => unsafe { node_mut(node) }.next = Some(other_head),
+            None => self.list.head = Some(other_head),
+        }
+        match continuation {
+            Some(node) => unsafe { node_mut(node) }.prev = Some(other_tail),
+            None => self.list.tail = Some(other_tail),
+        }
+    }
+}
+
+pub struct Iter<'a, T, const ID: u64 = 0> {
+    front: Option<NodePtr<T, ID>>,
+    back: Option<NodePtr<T, ID>>,
     _phantom: core::marker::PhantomData<&'a ()>,
 }
 
-impl<'a, T: 'a> Iterator for Iter<'a, T> {
+impl<'a, T: 'a, const ID: u64> Iterator for Iter<'a, T, ID> {
     type Item = &'a T;
     fn next(&mut self) -> Option<Self::Item> {
-        let current = self.current.take()?;
+        let current = self.front?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            // SAFE: valid by list impl.
+            self.front = unsafe { node_ref(current).next };
+        }
+        // SAFE: valid by list impl.
+        Some(unsafe { node_ref(current).value.as_ref() })
+    }
+}
+
+impl<'a, T: 'a, const ID: u64> DoubleEndedIterator for Iter<'a, T, ID> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let current = self.back?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            // SAFE: valid by list impl.
+            self.back = unsafe { node_ref(current).prev };
+        }
         // SAFE: valid by list impl.
-        let value_ref = unsafe { current.as_ref().value.as_ref() };
-        self.current = unsafe { current.as_ref().next };
-        Some(value_ref)
+        Some(unsafe { node_ref(current).value.as_ref() })
     }
 }
 
-impl<'a, T: 'a> core::iter::FusedIterator for Iter<'a, T> {}
+impl<'a, T: 'a, const ID: u64> core::iter::FusedIterator for Iter<'a, T, ID> {}
 
-pub struct IterMut<'a, T> {
-    current: Option<ptr::NonNull<IntrusiveListNode<T>>>,
+pub struct IterMut<'a, T, const ID: u64 = 0> {
+    front: Option<NodePtr<T, ID>>,
+    back: Option<NodePtr<T, ID>>,
     _phantom: core::marker::PhantomData<&'a ()>,
 }
-impl<'a, T: 'a> Iterator for IterMut<'a, T> {
+impl<'a, T: 'a, const ID: u64> Iterator for IterMut<'a, T, ID> {
     type Item = &'a mut T;
     fn next(&mut self) -> Option<Self::Item> {
-        let mut current = self.current.take()?;
+        let current = self.front?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            // SAFE: valid by list impl.
+            self.front = unsafe { node_ref(current).next };
+        }
         // SAFE: valid by list impl.
-        let value_ref = unsafe { current.as_mut().value.as_mut() };
-        self.current = unsafe { current.as_ref().next };
-        Some(value_ref)
+        Some(unsafe { node_mut(current).value.as_mut() })
     }
 }
-impl<'a, T: 'a> core::iter::FusedIterator for IterMut<'a, T> {}
+impl<'a, T: 'a, const ID: u64> DoubleEndedIterator for IterMut<'a, T, ID> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let current = self.back?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            // SAFE: valid by list impl.
+            self.back = unsafe { node_ref(current).prev };
+        }
+        // SAFE: valid by list impl.
+        Some(unsafe { node_mut(current).value.as_mut() })
+    }
+}
+impl<'a, T: 'a, const ID: u64> core::iter::FusedIterator for IterMut<'a, T, ID> {}
 
 #[cfg(test)]
 mod tests {
@@ -141,4 +486,209 @@ mod tests {
 
     #[test]
     fn with_cons() {}
+
+    #[test]
+    fn remove_current_non_innermost() {
+        let mut list: IntrusiveList<i32> = IntrusiveList::default();
+        let mut a = 1;
+        let mut b = 2;
+
+        list.with_cons(&mut a, |list| {
+            list.with_cons(&mut b, |list| {
+                // `a` is the outer, non-innermost frame's node; removing it from the innermost
+                // frame must not leave `a`'s own (now stale) `with_cons` unwind free to stomp
+                // `head`/`tail` with garbage once it eventually runs.
+                let mut cursor = list.cursor_mut();
+                cursor.move_next();
+                assert_eq!(cursor.remove_current(), Some(&mut 1));
+                assert_eq!(list.head(), Some(&2));
+                assert_eq!(list.tail(), Some(&2));
+            });
+            // `b`'s own `with_cons` has now unwound too (popping itself as usual), so the list is
+            // empty well before `a`'s own (outer, stale) frame gets a chance to unwind — if it
+            // trusted its own `next`/`prev` here instead of seeing `a.detached`, it would instead
+            // resurrect `tail` as a dangling pointer into `b`'s already-popped stack frame.
+            assert_eq!(list.head(), None);
+            assert_eq!(list.tail(), None);
+        });
+
+        assert_eq!(list.head(), None);
+        assert_eq!(list.tail(), None);
+    }
+
+    #[test]
+    fn splice_after_grafts_other_list() {
+        let mut list_a: IntrusiveList<i32> = IntrusiveList::default();
+        let mut list_b: IntrusiveList<i32> = IntrusiveList::default();
+        let mut a1 = 1;
+        let mut b1 = 2;
+        let mut b2 = 3;
+
+        // `list_b`'s frames enclose the splice and everywhere `list_a` is read afterwards, so the
+        // grafted nodes stay live for as long as `list_a` can reach them.
+        list_b.with_cons(&mut b1, |list_b| {
+            list_b.with_cons(&mut b2, |list_b| {
+                list_a.with_cons(&mut a1, |list_a| {
+                    list_a.cursor_mut().splice_after(list_b);
+                    assert_eq!(list_a.iter().copied().collect::<Vec<_>>(), vec![1, 3, 2]);
+                    assert_eq!(list_b.head(), None);
+                    assert_eq!(list_b.tail(), None);
+                });
+                assert_eq!(list_a.iter().copied().collect::<Vec<_>>(), vec![3, 2]);
+            });
+        });
+    }
+
+    #[test]
+    fn multi_membership() {
+        let mut ready: IntrusiveList<i32, 0> = IntrusiveList::default();
+        let mut all: IntrusiveList<i32, 1> = IntrusiveList::default();
+        let mut value = 42;
+
+        ready.with_cons(&mut value, |ready| {
+            // Reborrow the value straight out of `ready`'s own node instead of handing `all` an
+            // independently-derived `&mut i32` to the same place: the borrow checker then tracks
+            // that `ready` can't be touched again until this reborrow's `with_cons` call returns,
+            // so there is never more than one live `&mut i32` to `value`, just one handed onward.
+            all.with_cons(ready.head_mut().unwrap(), |all| {
+                assert_eq!(all.head(), Some(&42));
+            });
+            // `ready`'s reborrow has ended, so it's usable again; the value has been a member of
+            // both lists the whole time `all`'s `with_cons` call was running above.
+            assert_eq!(ready.head(), Some(&42));
+        });
+    }
+
+    #[test]
+    fn double_ended() {
+        let mut list: IntrusiveList<i32> = IntrusiveList::default();
+        let mut a = 1;
+        let mut b = 2;
+        let mut c = 3;
+
+        list.with_cons(&mut a, |list| {
+            list.with_cons(&mut b, |list| {
+                list.with_cons(&mut c, |list| {
+                    assert_eq!(list.head(), Some(&3));
+                    assert_eq!(list.tail(), Some(&1));
+                    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+                    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+                });
+            });
+        });
+    }
+
+    #[test]
+    fn tail_mut() {
+        let mut list: IntrusiveList<i32> = IntrusiveList::default();
+        let mut a = 1;
+        let mut b = 2;
+
+        list.with_cons(&mut a, |list| {
+            list.with_cons(&mut b, |list| {
+                *list.tail_mut().unwrap() += 10;
+                assert_eq!(list.tail(), Some(&11));
+                assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 11]);
+            });
+        });
+    }
+
+    #[test]
+    fn double_ended_interleaved() {
+        let mut list: IntrusiveList<i32> = IntrusiveList::default();
+        let mut a = 1;
+        let mut b = 2;
+        let mut c = 3;
+        let mut d = 4;
+
+        list.with_cons(&mut a, |list| {
+            list.with_cons(&mut b, |list| {
+                list.with_cons(&mut c, |list| {
+                    list.with_cons(&mut d, |list| {
+                        // Front-to-back: [4, 3, 2, 1]. Alternate ends on the *same* iterator so
+                        // `front`/`back` actually meet in the middle instead of each direction
+                        // being walked to exhaustion independently.
+                        let mut iter = list.iter();
+                        assert_eq!(iter.next(), Some(&4));
+                        assert_eq!(iter.next_back(), Some(&1));
+                        assert_eq!(iter.next(), Some(&3));
+                        assert_eq!(iter.next_back(), Some(&2));
+                        assert_eq!(iter.next(), None);
+                        assert_eq!(iter.next_back(), None);
+
+                        let mut iter_mut = list.iter_mut();
+                        assert_eq!(iter_mut.next(), Some(&mut 4));
+                        assert_eq!(iter_mut.next_back(), Some(&mut 1));
+                        assert_eq!(iter_mut.next(), Some(&mut 3));
+                        assert_eq!(iter_mut.next_back(), Some(&mut 2));
+                        assert_eq!(iter_mut.next(), None);
+                        assert_eq!(iter_mut.next_back(), None);
+                    });
+                });
+            });
+        });
+    }
+
+    #[test]
+    fn with_cons_slice() {
+        let mut list: IntrusiveList<i32> = IntrusiveList::default();
+        let mut values = [1, 2, 3];
+        let mut scratch = [const { MaybeUninit::uninit() }; 3];
+
+        list.with_cons_slice(&mut values, &mut scratch, |list| {
+            assert_eq!(list.head(), Some(&1));
+            assert_eq!(list.tail(), Some(&3));
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        });
+
+        assert_eq!(list.head(), None);
+        assert_eq!(list.tail(), None);
+    }
+
+    #[test]
+    fn with_cons_slice_remove_last_persists_rest() {
+        let mut list: IntrusiveList<i32> = IntrusiveList::default();
+        let mut values = [1, 2, 3];
+        let mut scratch = [const { MaybeUninit::uninit() }; 3];
+
+        list.with_cons_slice(&mut values, &mut scratch, |list| {
+            let mut cursor = list.cursor_mut();
+            cursor.move_next();
+            cursor.move_next();
+            assert_eq!(cursor.remove_current(), Some(&mut 3));
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+        });
+
+        // Removing the run's last node already wove `1`/`2` correctly into the list, so they stay
+        // linked in even after `with_cons_slice` returns, instead of being reverted along with it.
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn with_cons_slice_remove_first_persists_rest() {
+        let mut list: IntrusiveList<i32> = IntrusiveList::default();
+        let mut values = [1, 2, 3];
+        let mut scratch = [const { MaybeUninit::uninit() }; 3];
+
+        list.with_cons_slice(&mut values, &mut scratch, |list| {
+            let mut cursor = list.cursor_mut();
+            assert_eq!(cursor.remove_current(), Some(&mut 1));
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+        });
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn debug_uses_shared_iteration() {
+        let mut list: IntrusiveList<i32> = IntrusiveList::default();
+        let mut a = 1;
+        let mut b = 2;
+
+        list.with_cons(&mut a, |list| {
+            list.with_cons(&mut b, |list| {
+                assert_eq!(format!("{list:?}"), "[2, 1]");
+            });
+        });
+    }
 }